@@ -0,0 +1,90 @@
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+
+/// A typed byte offset of a field of type `F` within a struct of type `S`.
+///
+/// This is a zero-cost wrapper around a plain `usize` offset that additionally remembers which
+/// struct and field type it belongs to. It is generated by the `typed` mode of
+/// [`offsetable_struct!`](crate::offsetable_struct) and turns raw offset numbers into safe field
+/// projection from a base pointer.
+pub struct FieldOffset<S, F> {
+	offset: usize,
+	_marker: PhantomData<(*const S, F)>
+}
+impl<S, F> FieldOffset<S, F> {
+	/// Creates a new field offset from a byte offset.
+	pub const fn new(offset: usize) -> Self {
+		FieldOffset { offset, _marker: PhantomData }
+	}
+
+	/// Returns the byte offset this wrapper carries.
+	pub const fn offset(self) -> usize {
+		self.offset
+	}
+
+	/// Projects a base pointer to a `const` pointer to the field.
+	pub const fn get_ptr(self, base: *const S) -> *const F {
+		unsafe { (base as *const u8).add(self.offset) as *const F }
+	}
+
+	/// Projects a base pointer to a `mut` pointer to the field.
+	pub const fn get_mut_ptr(self, base: *mut S) -> *mut F {
+		unsafe { (base as *mut u8).add(self.offset) as *mut F }
+	}
+
+	/// Reads the field through the base pointer without assuming alignment.
+	///
+	/// This stays sound for `packed` structs, where the field may be under-aligned.
+	///
+	/// # Safety
+	/// `base` must point to a valid `S` and the field must be initialized.
+	pub unsafe fn read_unaligned(self, base: *const S) -> F {
+		self.get_ptr(base).read_unaligned()
+	}
+
+	/// Writes the field through the base pointer without assuming alignment.
+	///
+	/// This stays sound for `packed` structs, where the field may be under-aligned.
+	///
+	/// # Safety
+	/// `base` must point to a valid `S` that is safe to write through.
+	pub unsafe fn write_unaligned(self, base: *mut S, value: F) {
+		self.get_mut_ptr(base).write_unaligned(value)
+	}
+}
+
+// The wrapper only ever stores a `usize`, so all of the common traits are implemented in terms of
+// that offset and do not require any bounds on `S` or `F`.
+impl<S, F> Clone for FieldOffset<S, F> {
+	fn clone(&self) -> Self {
+		*self
+	}
+}
+impl<S, F> Copy for FieldOffset<S, F> {}
+impl<S, F> fmt::Debug for FieldOffset<S, F> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_tuple("FieldOffset").field(&self.offset).finish()
+	}
+}
+impl<S, F> PartialEq for FieldOffset<S, F> {
+	fn eq(&self, other: &Self) -> bool {
+		self.offset == other.offset
+	}
+}
+impl<S, F> Eq for FieldOffset<S, F> {}
+impl<S, F> PartialOrd for FieldOffset<S, F> {
+	fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+		Some(self.cmp(other))
+	}
+}
+impl<S, F> Ord for FieldOffset<S, F> {
+	fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+		self.offset.cmp(&other.offset)
+	}
+}
+impl<S, F> Hash for FieldOffset<S, F> {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.offset.hash(state)
+	}
+}
@@ -1,6 +1,21 @@
+pub mod field_offset;
 pub mod util;
 
-/// Creates a `repr(C)` struct and a companion offsets struct which represents byte offsets of the fields.
+/// A type whose field byte offsets are described by a companion `Offsets` struct.
+///
+/// This is implemented by [`offsetable_struct!`] for every generated struct and lets generic code
+/// recover the offsets struct via the associated [`Offsets`](Offsetable::Offsets) type. Combined with
+/// [`util::nested`] it allows walking a tree of nested offsetable structs and resolving the absolute
+/// byte offset of a leaf field, e.g. `util::nested(Outer::offsets().inner, Inner::offsets().field)`.
+pub trait Offsetable {
+	/// The companion struct describing this type's field offsets.
+	type Offsets;
+
+	/// Returns the offsets of this type's fields.
+	fn offsets() -> Self::Offsets;
+}
+
+/// Creates a `repr(C)` struct and companion structs describing the byte layout of its fields.
 ///
 /// ```
 /// # #[macro_use] extern crate offsetable_struct;
@@ -10,7 +25,7 @@ pub mod util;
 /// 		pub a: f32,
 /// 		pub b: [f32; 4],
 /// 		c: u8
-/// 	} repr(C) as NameOffsets
+/// 	} repr(C) as NameOffsets, NameSpans
 /// }
 /// ```
 ///
@@ -23,6 +38,13 @@ pub mod util;
 /// 	c: usize
 /// }
 ///
+/// #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
+/// pub struct NameSpans {
+/// 	pub a: (usize, usize),
+/// 	pub b: (usize, usize),
+/// 	c: (usize, usize)
+/// }
+///
 /// #[derive(Debug)]
 /// #[repr(C)]
 /// pub struct Name {
@@ -31,66 +53,199 @@ pub mod util;
 /// 	c: u8
 /// }
 /// impl Name {
-/// 	#[allow(unused_variables)]
+/// 	#[allow(unused_variables, unused_assignments)]
 /// 	pub const fn offsets() -> NameOffsets {
 /// 		let current_offset: usize = 0;
 ///
 /// 		let a = offsetable_struct::util::align_up(
 /// 			current_offset,
-/// 			std::mem::align_of::<f32>()
+/// 			offsetable_struct::util::align_min(std::mem::align_of::<f32>(), usize::MAX)
 /// 		);
 /// 		let current_offset = a + std::mem::size_of::<f32>();
 ///
-/// 		let b = offsetable_struct::util::align_up(
-/// 			current_offset,
-/// 			std::mem::align_of::<[f32; 4]>()
-/// 		);
-/// 		let current_offset = b + std::mem::size_of::<[f32; 4]>();
-///
-/// 		let c = offsetable_struct::util::align_up(
-/// 			current_offset,
-/// 			std::mem::align_of::<u8>()
-/// 		);
-/// 		let current_offset = c + std::mem::size_of::<u8>();
+/// 		// ... one block per field ...
+/// 		# let b = a; let c = b;
 ///
 /// 		NameOffsets { a, b, c }
 /// 	}
 /// }
 /// ```
+///
+/// The trailing `repr(...)` selector controls the layout of the generated struct. In addition to the
+/// plain `repr(C)` it accepts the packed and aligned forms the compiler understands:
+///
+/// * `repr(C, packed)` and `repr(C, packed(N))` cap each field's effective alignment at `N` (plain
+///   `packed` meaning `N == 1`), matching how the compiler removes inter-field padding.
+/// * `repr(C, align(N))` leaves field placement untouched but raises the struct's alignment to at
+///   least `N`.
+///
+/// The emitted struct carries the matching `#[repr(...)]` and `offsets()` uses the effective field
+/// alignment so the reported offsets stay consistent with the real layout.
+///
+/// Alongside `NameOffsets` a `NameSpans` companion is generated. `field_spans()` returns, per field,
+/// the `(start, end)` byte range it occupies (`start == offset`, `end == offset + size`), while
+/// `packed_size()` returns the struct's total size rounded up to its alignment. Together they let a
+/// caller slice a `&[u8]` blob into field regions and know the padded stride.
+///
+/// Adding the `typed` keyword before `as` (e.g. `} repr(C) typed as NameOffsets, NameSpans`) makes
+/// each `NameOffsets` field a [`FieldOffset<Name, FieldType>`](crate::field_offset::FieldOffset)
+/// instead of a plain `usize`, which projects a base pointer straight to a typed field pointer.
+///
+/// Generic type parameters (with single-trait-bound syntax), an optional `where` clause, and
+/// tuple-struct bodies with numeric field access are all accepted, e.g.
+/// `pub struct Name<T: Copy>(pub T, u32) where T: Default repr(C) as NameOffsets, NameSpans`. The
+/// generics and `where` clause are propagated to the generated struct and its `impl` blocks, and
+/// `offsets()` stays a `const fn` for any monomorphization.
 #[macro_export]
 macro_rules! offsetable_struct {
+	//
+	// Internal arms. These thread the generics (`gdecl` = declaration form with bounds, `guse` = use
+	// form without bounds) and `where` clause (`whr`) as raw token sections so they can be re-emitted
+	// verbatim on the data struct and every `impl` block.
+	//
+
+	// Named structs: resolve the optional `typed` keyword into an explicit mode token.
 	(
+		@named
 		$( #[$attribute: meta] )*
-		$struct_vis: vis struct $name: ident {
-			$(
-				$field_vis: vis $field: ident: $ftype: ty
-			),*
-		} repr(C) as $offsets_name: ident
+		$struct_vis: vis struct $name: ident
+		gdecl[ $($gdecl: tt)* ] guse[ $($guse: tt)* ] whr[ $($whr: tt)* ]
+		{ $( $field_vis: vis $field: ident: $ftype: ty ),* }
+		cap $cap: expr, floor $floor: expr, repr[ $($repr_tok: tt)* ]
+		rest( typed as $offsets_name: ident, $spans_name: ident )
+	) => {
+		$crate::offsetable_struct! {
+			@named_build typed
+			$( #[$attribute] )*
+			$struct_vis struct $name
+			gdecl[ $($gdecl)* ] guse[ $($guse)* ] whr[ $($whr)* ]
+			{ $( $field_vis $field: $ftype ),* }
+			cap $cap, floor $floor, repr[ $($repr_tok)* ] => $offsets_name, $spans_name
+		}
+	};
+	(
+		@named
+		$( #[$attribute: meta] )*
+		$struct_vis: vis struct $name: ident
+		gdecl[ $($gdecl: tt)* ] guse[ $($guse: tt)* ] whr[ $($whr: tt)* ]
+		{ $( $field_vis: vis $field: ident: $ftype: ty ),* }
+		cap $cap: expr, floor $floor: expr, repr[ $($repr_tok: tt)* ]
+		rest( as $offsets_name: ident, $spans_name: ident )
+	) => {
+		$crate::offsetable_struct! {
+			@named_build plain
+			$( #[$attribute] )*
+			$struct_vis struct $name
+			gdecl[ $($gdecl)* ] guse[ $($guse)* ] whr[ $($whr)* ]
+			{ $( $field_vis $field: $ftype ),* }
+			cap $cap, floor $floor, repr[ $($repr_tok)* ] => $offsets_name, $spans_name
+		}
+	};
+
+	// Named structs: emit the data struct, the spans companion and the mode-independent accessors,
+	// then delegate the offsets companion to a mode-specific arm.
+	(
+		@named_build $mode: ident
+		$( #[$attribute: meta] )*
+		$struct_vis: vis struct $name: ident
+		gdecl[ $($gdecl: tt)* ] guse[ $($guse: tt)* ] whr[ $($whr: tt)* ]
+		{ $( $field_vis: vis $field: ident: $ftype: ty ),* }
+		cap $cap: expr, floor $floor: expr, repr[ $($repr_tok: tt)* ] => $offsets_name: ident, $spans_name: ident
 	) => {
 		#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
-		$struct_vis struct $offsets_name {
+		$struct_vis struct $spans_name {
 			$(
-				$field_vis $field: usize
+				$field_vis $field: (usize, usize)
 			),*
 		}
 
 		$( #[$attribute] )*
-		#[repr(C)]
-		$struct_vis struct $name {
+		#[repr($($repr_tok)*)]
+		$struct_vis struct $name $($gdecl)* $($whr)* {
 			$(
 				$field_vis $field: $ftype
 			),*
 		}
-		impl $name {
+		impl $($gdecl)* $name $($guse)* $($whr)* {
+			/// Returns a struct describing the `(start, end)` byte range of each field.
+			///
+			/// `start` is the field offset and `end` is `start + size_of::<field>()`, so the range can
+			/// be used directly to slice a byte blob into per-field regions.
+			#[allow(unused_variables, unused_assignments)]
+			pub const fn field_spans() -> $spans_name {
+				let current_offset: usize = 0;
+
+				$(
+					let start = $crate::util::align_up(
+						current_offset,
+						$crate::util::align_min(std::mem::align_of::<$ftype>(), $cap)
+					);
+					let current_offset = start + std::mem::size_of::<$ftype>();
+					let $field = (start, current_offset);
+				)*
+
+				$spans_name {
+					$(
+						$field
+					),*
+				}
+			}
+
+			/// Returns the total size of the struct, including trailing padding up to its alignment.
+			///
+			/// The alignment is the largest effective field alignment, raised to the `align(N)` floor
+			/// when one is requested, so this matches the stride the compiler lays out.
+			#[allow(unused_variables, unused_assignments)]
+			pub const fn packed_size() -> usize {
+				let current_offset: usize = 0;
+				let struct_align: usize = $floor;
+
+				$(
+					let field_align = $crate::util::align_min(std::mem::align_of::<$ftype>(), $cap);
+					let start = $crate::util::align_up(current_offset, field_align);
+					let current_offset = start + std::mem::size_of::<$ftype>();
+					let struct_align = $crate::util::align_max(struct_align, field_align);
+				)*
+
+				$crate::util::align_up(current_offset, struct_align)
+			}
+		}
+
+		$crate::offsetable_struct! {
+			@named_offsets $mode
+			$struct_vis struct $name
+			gdecl[ $($gdecl)* ] guse[ $($guse)* ] whr[ $($whr)* ] selfty( $name $($guse)* )
+			{ $( $field_vis $field: $ftype ),* } cap $cap => $offsets_name
+		}
+	};
+
+	// Named plain mode: each offset is a `usize`.
+	(
+		@named_offsets plain
+		$struct_vis: vis struct $name: ident
+		gdecl[ $($gdecl: tt)* ] guse[ $($guse: tt)* ] whr[ $($whr: tt)* ] selfty( $selfty: ty )
+		{ $( $field_vis: vis $field: ident: $ftype: ty ),* } cap $cap: expr => $offsets_name: ident
+	) => {
+		#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
+		$struct_vis struct $offsets_name {
+			$(
+				$field_vis $field: usize
+			),*
+		}
+
+		impl $($gdecl)* $name $($guse)* $($whr)* {
 			/// Returns a struct describing offsets of each field from the start of the struct.
 			///
 			/// This is mainly useful for things like vertex data.
-			#[allow(unused_variables)]
+			#[allow(unused_variables, unused_assignments)]
 			pub const fn offsets() -> $offsets_name {
 				let current_offset: usize = 0;
 
 				$(
-					let $field = $crate::util::align_up(current_offset, std::mem::align_of::<$ftype>());
+					let $field = $crate::util::align_up(
+						current_offset,
+						$crate::util::align_min(std::mem::align_of::<$ftype>(), $cap)
+					);
 					let current_offset = $field + std::mem::size_of::<$ftype>();
 				)*
 
@@ -101,10 +256,528 @@ macro_rules! offsetable_struct {
 				}
 			}
 		}
-	}
+
+		impl $($gdecl)* $crate::Offsetable for $name $($guse)* $($whr)* {
+			type Offsets = $offsets_name;
+
+			fn offsets() -> $offsets_name {
+				Self::offsets()
+			}
+		}
+	};
+
+	// Named typed mode: each offset is a `FieldOffset<Name, FieldType>` wrapper, so the offsets
+	// companion carries the same generics as the data struct.
+	(
+		@named_offsets typed
+		$struct_vis: vis struct $name: ident
+		gdecl[ $($gdecl: tt)* ] guse[ $($guse: tt)* ] whr[ $($whr: tt)* ] selfty( $selfty: ty )
+		{ $( $field_vis: vis $field: ident: $ftype: ty ),* } cap $cap: expr => $offsets_name: ident
+	) => {
+		#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
+		$struct_vis struct $offsets_name $($gdecl)* $($whr)* {
+			$(
+				$field_vis $field: $crate::field_offset::FieldOffset<$selfty, $ftype>
+			),*
+		}
+
+		impl $($gdecl)* $name $($guse)* $($whr)* {
+			/// Returns a struct with a typed [`FieldOffset`](crate::field_offset::FieldOffset) for each
+			/// field, which can project a base pointer to a typed field pointer.
+			#[allow(unused_variables, unused_assignments)]
+			pub const fn offsets() -> $offsets_name $($guse)* {
+				let current_offset: usize = 0;
+
+				$(
+					let $field = $crate::util::align_up(
+						current_offset,
+						$crate::util::align_min(std::mem::align_of::<$ftype>(), $cap)
+					);
+					let current_offset = $field + std::mem::size_of::<$ftype>();
+				)*
+
+				$offsets_name {
+					$(
+						$field: $crate::field_offset::FieldOffset::new($field)
+					),*
+				}
+			}
+		}
+
+		impl $($gdecl)* $crate::Offsetable for $name $($guse)* $($whr)* {
+			type Offsets = $offsets_name $($guse)*;
+
+			fn offsets() -> $offsets_name $($guse)* {
+				Self::offsets()
+			}
+		}
+	};
+
+	// Tuple structs: resolve the optional `typed` keyword into an explicit mode token.
+	(
+		@tuple
+		$( #[$attribute: meta] )*
+		$struct_vis: vis struct $name: ident
+		gdecl[ $($gdecl: tt)* ] guse[ $($guse: tt)* ] whr[ $($whr: tt)* ]
+		( $( $field_vis: vis $ftype: ty ),* )
+		cap $cap: expr, floor $floor: expr, repr[ $($repr_tok: tt)* ]
+		rest( typed as $offsets_name: ident, $spans_name: ident )
+	) => {
+		$crate::offsetable_struct! {
+			@tuple_build typed
+			$( #[$attribute] )*
+			$struct_vis struct $name
+			gdecl[ $($gdecl)* ] guse[ $($guse)* ] whr[ $($whr)* ]
+			( $( $field_vis $ftype ),* )
+			cap $cap, floor $floor, repr[ $($repr_tok)* ] => $offsets_name, $spans_name
+		}
+	};
+	(
+		@tuple
+		$( #[$attribute: meta] )*
+		$struct_vis: vis struct $name: ident
+		gdecl[ $($gdecl: tt)* ] guse[ $($guse: tt)* ] whr[ $($whr: tt)* ]
+		( $( $field_vis: vis $ftype: ty ),* )
+		cap $cap: expr, floor $floor: expr, repr[ $($repr_tok: tt)* ]
+		rest( as $offsets_name: ident, $spans_name: ident )
+	) => {
+		$crate::offsetable_struct! {
+			@tuple_build plain
+			$( #[$attribute] )*
+			$struct_vis struct $name
+			gdecl[ $($gdecl)* ] guse[ $($guse)* ] whr[ $($whr)* ]
+			( $( $field_vis $ftype ),* )
+			cap $cap, floor $floor, repr[ $($repr_tok)* ] => $offsets_name, $spans_name
+		}
+	};
+
+	// Tuple structs: emit the data struct, the spans companion and the mode-independent accessors.
+	(
+		@tuple_build $mode: ident
+		$( #[$attribute: meta] )*
+		$struct_vis: vis struct $name: ident
+		gdecl[ $($gdecl: tt)* ] guse[ $($guse: tt)* ] whr[ $($whr: tt)* ]
+		( $( $field_vis: vis $ftype: ty ),* )
+		cap $cap: expr, floor $floor: expr, repr[ $($repr_tok: tt)* ] => $offsets_name: ident, $spans_name: ident
+	) => {
+		#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
+		$struct_vis struct $spans_name (
+			$(
+				$field_vis (usize, usize)
+			),*
+		);
+
+		$( #[$attribute] )*
+		#[repr($($repr_tok)*)]
+		$struct_vis struct $name $($gdecl)* (
+			$(
+				$field_vis $ftype
+			),*
+		) $($whr)* ;
+		impl $($gdecl)* $name $($guse)* $($whr)* {
+			/// Returns a struct describing the `(start, end)` byte range of each field.
+			///
+			/// `start` is the field offset and `end` is `start + size_of::<field>()`, so the range can
+			/// be used directly to slice a byte blob into per-field regions.
+			#[allow(unused_variables, unused_assignments)]
+			pub const fn field_spans() -> $spans_name {
+				let mut current_offset: usize = 0;
+
+				$spans_name (
+					$({
+						let start = $crate::util::align_up(
+							current_offset,
+							$crate::util::align_min(std::mem::align_of::<$ftype>(), $cap)
+						);
+						current_offset = start + std::mem::size_of::<$ftype>();
+						(start, current_offset)
+					}),*
+				)
+			}
+
+			/// Returns the total size of the struct, including trailing padding up to its alignment.
+			///
+			/// The alignment is the largest effective field alignment, raised to the `align(N)` floor
+			/// when one is requested, so this matches the stride the compiler lays out.
+			#[allow(unused_variables, unused_assignments)]
+			pub const fn packed_size() -> usize {
+				let mut current_offset: usize = 0;
+				let mut struct_align: usize = $floor;
+
+				$({
+					let field_align = $crate::util::align_min(std::mem::align_of::<$ftype>(), $cap);
+					current_offset = $crate::util::align_up(current_offset, field_align) + std::mem::size_of::<$ftype>();
+					struct_align = $crate::util::align_max(struct_align, field_align);
+				})*
+
+				$crate::util::align_up(current_offset, struct_align)
+			}
+		}
+
+		$crate::offsetable_struct! {
+			@tuple_offsets $mode
+			$struct_vis struct $name
+			gdecl[ $($gdecl)* ] guse[ $($guse)* ] whr[ $($whr)* ] selfty( $name $($guse)* )
+			( $( $field_vis $ftype ),* ) cap $cap => $offsets_name
+		}
+	};
+
+	// Tuple plain mode: each offset is a `usize`.
+	(
+		@tuple_offsets plain
+		$struct_vis: vis struct $name: ident
+		gdecl[ $($gdecl: tt)* ] guse[ $($guse: tt)* ] whr[ $($whr: tt)* ] selfty( $selfty: ty )
+		( $( $field_vis: vis $ftype: ty ),* ) cap $cap: expr => $offsets_name: ident
+	) => {
+		#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
+		$struct_vis struct $offsets_name (
+			$(
+				$field_vis usize
+			),*
+		);
+
+		impl $($gdecl)* $name $($guse)* $($whr)* {
+			/// Returns a struct describing offsets of each field from the start of the struct.
+			///
+			/// This is mainly useful for things like vertex data.
+			#[allow(unused_variables, unused_assignments)]
+			pub const fn offsets() -> $offsets_name {
+				let mut current_offset: usize = 0;
+
+				$offsets_name (
+					$({
+						let offset = $crate::util::align_up(
+							current_offset,
+							$crate::util::align_min(std::mem::align_of::<$ftype>(), $cap)
+						);
+						current_offset = offset + std::mem::size_of::<$ftype>();
+						offset
+					}),*
+				)
+			}
+		}
+
+		impl $($gdecl)* $crate::Offsetable for $name $($guse)* $($whr)* {
+			type Offsets = $offsets_name;
+
+			fn offsets() -> $offsets_name {
+				Self::offsets()
+			}
+		}
+	};
+
+	// Tuple typed mode: each offset is a `FieldOffset<Name, FieldType>` wrapper.
+	(
+		@tuple_offsets typed
+		$struct_vis: vis struct $name: ident
+		gdecl[ $($gdecl: tt)* ] guse[ $($guse: tt)* ] whr[ $($whr: tt)* ] selfty( $selfty: ty )
+		( $( $field_vis: vis $ftype: ty ),* ) cap $cap: expr => $offsets_name: ident
+	) => {
+		#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
+		$struct_vis struct $offsets_name $($gdecl)* (
+			$(
+				$field_vis $crate::field_offset::FieldOffset<$selfty, $ftype>
+			),*
+		) $($whr)* ;
+
+		impl $($gdecl)* $name $($guse)* $($whr)* {
+			/// Returns a struct with a typed [`FieldOffset`](crate::field_offset::FieldOffset) for each
+			/// field, which can project a base pointer to a typed field pointer.
+			#[allow(unused_variables, unused_assignments)]
+			pub const fn offsets() -> $offsets_name $($guse)* {
+				let mut current_offset: usize = 0;
+
+				$offsets_name (
+					$({
+						let offset = $crate::util::align_up(
+							current_offset,
+							$crate::util::align_min(std::mem::align_of::<$ftype>(), $cap)
+						);
+						current_offset = offset + std::mem::size_of::<$ftype>();
+						$crate::field_offset::FieldOffset::new(offset)
+					}),*
+				)
+			}
+		}
+
+		impl $($gdecl)* $crate::Offsetable for $name $($guse)* $($whr)* {
+			type Offsets = $offsets_name $($guse)*;
+
+			fn offsets() -> $offsets_name $($guse)* {
+				Self::offsets()
+			}
+		}
+	};
+
+	//
+	// Surface arms. These capture the generics once (pre-computing the declaration and use forms),
+	// then peel the optional `where` clause off token-by-token before resolving the `repr(...)`
+	// selector into an effective field-alignment cap and an alignment floor. The `where` clause is
+	// munched rather than matched as a single `$(where $($whr:tt)+)?` because that repetition is
+	// locally ambiguous when immediately followed by the `repr` token (which is itself a `tt`).
+	//
+
+	// Named struct entry: start the `where`-peeling muncher with an empty accumulator.
+	(
+		$( #[$attribute: meta] )*
+		$struct_vis: vis struct $name: ident
+		$(< $( $gen: ident $(: $gbound: path )? ),+ >)?
+		{ $( $field_vis: vis $field: ident: $ftype: ty ),* }
+		$( $tail: tt )*
+	) => {
+		$crate::offsetable_struct! {
+			@named_peel
+			$( #[$attribute] )*
+			$struct_vis struct $name
+			gdecl[ $(< $( $gen $(: $gbound )? ),+ >)? ]
+			guse[ $(< $( $gen ),+ >)? ]
+			{ $( $field_vis $field: $ftype ),* }
+			whr[ ] $( $tail )*
+		}
+	};
+	// Named muncher: the next token opens the `repr(...)` selector, so the `where` clause is complete.
+	(
+		@named_peel
+		$( #[$attribute: meta] )*
+		$struct_vis: vis struct $name: ident
+		gdecl[ $($gdecl: tt)* ] guse[ $($guse: tt)* ]
+		{ $( $field_vis: vis $field: ident: $ftype: ty ),* }
+		whr[ $($whr: tt)* ] repr($($repr_tok: tt)*) $($rest: tt)*
+	) => {
+		$crate::offsetable_struct! {
+			@named_repr
+			$( #[$attribute] )*
+			$struct_vis struct $name
+			gdecl[ $($gdecl)* ] guse[ $($guse)* ] whr[ $($whr)* ]
+			{ $( $field_vis $field: $ftype ),* }
+			repr( $($repr_tok)* ) rest( $($rest)* )
+		}
+	};
+	// Named muncher: accumulate one more `where`-clause token and recurse.
+	(
+		@named_peel
+		$( #[$attribute: meta] )*
+		$struct_vis: vis struct $name: ident
+		gdecl[ $($gdecl: tt)* ] guse[ $($guse: tt)* ]
+		{ $( $field_vis: vis $field: ident: $ftype: ty ),* }
+		whr[ $($whr: tt)* ] $tok: tt $($rest: tt)*
+	) => {
+		$crate::offsetable_struct! {
+			@named_peel
+			$( #[$attribute] )*
+			$struct_vis struct $name
+			gdecl[ $($gdecl)* ] guse[ $($guse)* ]
+			{ $( $field_vis $field: $ftype ),* }
+			whr[ $($whr)* $tok ] $($rest)*
+		}
+	};
+
+	// Named repr resolution: map each accepted `repr(...)` onto its field-alignment cap and floor.
+	(
+		@named_repr
+		$( #[$attribute: meta] )*
+		$struct_vis: vis struct $name: ident
+		gdecl[ $($gdecl: tt)* ] guse[ $($guse: tt)* ] whr[ $($whr: tt)* ]
+		{ $( $field_vis: vis $field: ident: $ftype: ty ),* }
+		repr( C ) rest( $($rest: tt)* )
+	) => {
+		$crate::offsetable_struct! {
+			@named
+			$( #[$attribute] )*
+			$struct_vis struct $name
+			gdecl[ $($gdecl)* ] guse[ $($guse)* ] whr[ $($whr)* ]
+			{ $( $field_vis $field: $ftype ),* }
+			cap usize::MAX, floor 1, repr[ C ]
+			rest( $($rest)* )
+		}
+	};
+	(
+		@named_repr
+		$( #[$attribute: meta] )*
+		$struct_vis: vis struct $name: ident
+		gdecl[ $($gdecl: tt)* ] guse[ $($guse: tt)* ] whr[ $($whr: tt)* ]
+		{ $( $field_vis: vis $field: ident: $ftype: ty ),* }
+		repr( C, packed ) rest( $($rest: tt)* )
+	) => {
+		$crate::offsetable_struct! {
+			@named
+			$( #[$attribute] )*
+			$struct_vis struct $name
+			gdecl[ $($gdecl)* ] guse[ $($guse)* ] whr[ $($whr)* ]
+			{ $( $field_vis $field: $ftype ),* }
+			cap 1, floor 1, repr[ C, packed ]
+			rest( $($rest)* )
+		}
+	};
+	(
+		@named_repr
+		$( #[$attribute: meta] )*
+		$struct_vis: vis struct $name: ident
+		gdecl[ $($gdecl: tt)* ] guse[ $($guse: tt)* ] whr[ $($whr: tt)* ]
+		{ $( $field_vis: vis $field: ident: $ftype: ty ),* }
+		repr( C, packed($pack: literal) ) rest( $($rest: tt)* )
+	) => {
+		$crate::offsetable_struct! {
+			@named
+			$( #[$attribute] )*
+			$struct_vis struct $name
+			gdecl[ $($gdecl)* ] guse[ $($guse)* ] whr[ $($whr)* ]
+			{ $( $field_vis $field: $ftype ),* }
+			cap $pack, floor 1, repr[ C, packed($pack) ]
+			rest( $($rest)* )
+		}
+	};
+	(
+		@named_repr
+		$( #[$attribute: meta] )*
+		$struct_vis: vis struct $name: ident
+		gdecl[ $($gdecl: tt)* ] guse[ $($guse: tt)* ] whr[ $($whr: tt)* ]
+		{ $( $field_vis: vis $field: ident: $ftype: ty ),* }
+		repr( C, align($align: literal) ) rest( $($rest: tt)* )
+	) => {
+		$crate::offsetable_struct! {
+			@named
+			$( #[$attribute] )*
+			$struct_vis struct $name
+			gdecl[ $($gdecl)* ] guse[ $($guse)* ] whr[ $($whr)* ]
+			{ $( $field_vis $field: $ftype ),* }
+			cap usize::MAX, floor $align, repr[ C, align($align) ]
+			rest( $($rest)* )
+		}
+	};
+
+	// Tuple struct entry: start the `where`-peeling muncher with an empty accumulator.
+	(
+		$( #[$attribute: meta] )*
+		$struct_vis: vis struct $name: ident
+		$(< $( $gen: ident $(: $gbound: path )? ),+ >)?
+		( $( $field_vis: vis $ftype: ty ),* )
+		$( $tail: tt )*
+	) => {
+		$crate::offsetable_struct! {
+			@tuple_peel
+			$( #[$attribute] )*
+			$struct_vis struct $name
+			gdecl[ $(< $( $gen $(: $gbound )? ),+ >)? ]
+			guse[ $(< $( $gen ),+ >)? ]
+			( $( $field_vis $ftype ),* )
+			whr[ ] $( $tail )*
+		}
+	};
+	// Tuple muncher: the next token opens the `repr(...)` selector, so the `where` clause is complete.
+	(
+		@tuple_peel
+		$( #[$attribute: meta] )*
+		$struct_vis: vis struct $name: ident
+		gdecl[ $($gdecl: tt)* ] guse[ $($guse: tt)* ]
+		( $( $field_vis: vis $ftype: ty ),* )
+		whr[ $($whr: tt)* ] repr($($repr_tok: tt)*) $($rest: tt)*
+	) => {
+		$crate::offsetable_struct! {
+			@tuple_repr
+			$( #[$attribute] )*
+			$struct_vis struct $name
+			gdecl[ $($gdecl)* ] guse[ $($guse)* ] whr[ $($whr)* ]
+			( $( $field_vis $ftype ),* )
+			repr( $($repr_tok)* ) rest( $($rest)* )
+		}
+	};
+	// Tuple muncher: accumulate one more `where`-clause token and recurse.
+	(
+		@tuple_peel
+		$( #[$attribute: meta] )*
+		$struct_vis: vis struct $name: ident
+		gdecl[ $($gdecl: tt)* ] guse[ $($guse: tt)* ]
+		( $( $field_vis: vis $ftype: ty ),* )
+		whr[ $($whr: tt)* ] $tok: tt $($rest: tt)*
+	) => {
+		$crate::offsetable_struct! {
+			@tuple_peel
+			$( #[$attribute] )*
+			$struct_vis struct $name
+			gdecl[ $($gdecl)* ] guse[ $($guse)* ]
+			( $( $field_vis $ftype ),* )
+			whr[ $($whr)* $tok ] $($rest)*
+		}
+	};
+
+	// Tuple repr resolution: map each accepted `repr(...)` onto its field-alignment cap and floor.
+	(
+		@tuple_repr
+		$( #[$attribute: meta] )*
+		$struct_vis: vis struct $name: ident
+		gdecl[ $($gdecl: tt)* ] guse[ $($guse: tt)* ] whr[ $($whr: tt)* ]
+		( $( $field_vis: vis $ftype: ty ),* )
+		repr( C ) rest( $($rest: tt)* )
+	) => {
+		$crate::offsetable_struct! {
+			@tuple
+			$( #[$attribute] )*
+			$struct_vis struct $name
+			gdecl[ $($gdecl)* ] guse[ $($guse)* ] whr[ $($whr)* ]
+			( $( $field_vis $ftype ),* )
+			cap usize::MAX, floor 1, repr[ C ]
+			rest( $($rest)* )
+		}
+	};
+	(
+		@tuple_repr
+		$( #[$attribute: meta] )*
+		$struct_vis: vis struct $name: ident
+		gdecl[ $($gdecl: tt)* ] guse[ $($guse: tt)* ] whr[ $($whr: tt)* ]
+		( $( $field_vis: vis $ftype: ty ),* )
+		repr( C, packed ) rest( $($rest: tt)* )
+	) => {
+		$crate::offsetable_struct! {
+			@tuple
+			$( #[$attribute] )*
+			$struct_vis struct $name
+			gdecl[ $($gdecl)* ] guse[ $($guse)* ] whr[ $($whr)* ]
+			( $( $field_vis $ftype ),* )
+			cap 1, floor 1, repr[ C, packed ]
+			rest( $($rest)* )
+		}
+	};
+	(
+		@tuple_repr
+		$( #[$attribute: meta] )*
+		$struct_vis: vis struct $name: ident
+		gdecl[ $($gdecl: tt)* ] guse[ $($guse: tt)* ] whr[ $($whr: tt)* ]
+		( $( $field_vis: vis $ftype: ty ),* )
+		repr( C, packed($pack: literal) ) rest( $($rest: tt)* )
+	) => {
+		$crate::offsetable_struct! {
+			@tuple
+			$( #[$attribute] )*
+			$struct_vis struct $name
+			gdecl[ $($gdecl)* ] guse[ $($guse)* ] whr[ $($whr)* ]
+			( $( $field_vis $ftype ),* )
+			cap $pack, floor 1, repr[ C, packed($pack) ]
+			rest( $($rest)* )
+		}
+	};
+	(
+		@tuple_repr
+		$( #[$attribute: meta] )*
+		$struct_vis: vis struct $name: ident
+		gdecl[ $($gdecl: tt)* ] guse[ $($guse: tt)* ] whr[ $($whr: tt)* ]
+		( $( $field_vis: vis $ftype: ty ),* )
+		repr( C, align($align: literal) ) rest( $($rest: tt)* )
+	) => {
+		$crate::offsetable_struct! {
+			@tuple
+			$( #[$attribute] )*
+			$struct_vis struct $name
+			gdecl[ $($gdecl)* ] guse[ $($guse)* ] whr[ $($whr)* ]
+			( $( $field_vis $ftype ),* )
+			cap usize::MAX, floor $align, repr[ C, align($align) ]
+			rest( $($rest)* )
+		}
+	};
 }
 
 #[cfg(test)]
+#[allow(dead_code)]
 mod tests {
 	use super::*;
 
@@ -116,11 +789,261 @@ mod tests {
 				pub a: f32,
 				pub b: [f32; 4],
 				c: u8
-			} repr(C) as FooOffsets
+			} repr(C) as FooOffsets, FooSpans
 		}
 
 		assert_eq!(Foo::offsets().a, 0);
 		assert_eq!(Foo::offsets().b, 4);
 		assert_eq!(Foo::offsets().c, 5 * 4);
 	}
+
+	#[test]
+	fn packed_removes_padding() {
+		offsetable_struct! {
+			#[derive(Debug)]
+			pub struct Packed {
+				pub a: u8,
+				pub b: u32,
+				pub c: u8
+			} repr(C, packed) as PackedOffsets, PackedSpans
+		}
+
+		assert_eq!(Packed::offsets().a, 0);
+		assert_eq!(Packed::offsets().b, 1);
+		assert_eq!(Packed::offsets().c, 5);
+	}
+
+	#[test]
+	fn packed_n_caps_alignment() {
+		offsetable_struct! {
+			#[derive(Debug)]
+			pub struct Packed2 {
+				pub a: u8,
+				pub b: u32,
+				pub c: u8
+			} repr(C, packed(2)) as Packed2Offsets, Packed2Spans
+		}
+
+		assert_eq!(Packed2::offsets().a, 0);
+		assert_eq!(Packed2::offsets().b, 2);
+		assert_eq!(Packed2::offsets().c, 6);
+	}
+
+	#[test]
+	fn align_keeps_field_offsets() {
+		offsetable_struct! {
+			#[derive(Debug)]
+			pub struct Aligned {
+				pub a: f32,
+				pub b: [f32; 4],
+				c: u8
+			} repr(C, align(16)) as AlignedOffsets, AlignedSpans
+		}
+
+		assert_eq!(Aligned::offsets().a, 0);
+		assert_eq!(Aligned::offsets().b, 4);
+		assert_eq!(Aligned::offsets().c, 5 * 4);
+	}
+
+	#[test]
+	fn spans_and_packed_size() {
+		offsetable_struct! {
+			#[derive(Debug)]
+			pub struct Vertex {
+				pub a: f32,
+				pub b: [f32; 4],
+				c: u8
+			} repr(C) as VertexOffsets, VertexSpans
+		}
+
+		assert_eq!(Vertex::field_spans().a, (0, 4));
+		assert_eq!(Vertex::field_spans().b, (4, 20));
+		assert_eq!(Vertex::field_spans().c, (20, 21));
+
+		// struct alignment is 4 (from the f32 fields), so 21 rounds up to 24.
+		assert_eq!(Vertex::packed_size(), 24);
+	}
+
+	#[test]
+	fn packed_size_is_tight_when_packed() {
+		offsetable_struct! {
+			#[derive(Debug)]
+			pub struct TightPacked {
+				pub a: u8,
+				pub b: u32,
+				pub c: u8
+			} repr(C, packed) as TightPackedOffsets, TightPackedSpans
+		}
+
+		assert_eq!(TightPacked::packed_size(), 6);
+	}
+
+	#[test]
+	fn packed_size_respects_align_floor() {
+		offsetable_struct! {
+			#[derive(Debug)]
+			pub struct AlignedSize {
+				pub a: f32,
+				pub b: [f32; 4],
+				c: u8
+			} repr(C, align(16)) as AlignedSizeOffsets, AlignedSizeSpans
+		}
+
+		// 21 bytes of data rounded up to the 16-byte alignment floor.
+		assert_eq!(AlignedSize::packed_size(), 32);
+	}
+
+	#[test]
+	fn typed_offsets_project_pointers() {
+		offsetable_struct! {
+			#[derive(Debug)]
+			pub struct Typed {
+				pub a: f32,
+				pub b: u32
+			} repr(C) typed as TypedOffsets, TypedSpans
+		}
+
+		let value = Typed { a: 1.0, b: 7 };
+		let offsets = Typed::offsets();
+
+		assert_eq!(offsets.b.offset(), 4);
+
+		let base = &value as *const Typed;
+		let b_ptr = offsets.b.get_ptr(base);
+		assert_eq!(unsafe { *b_ptr }, 7);
+	}
+
+	#[test]
+	fn typed_offsets_read_write_unaligned_when_packed() {
+		offsetable_struct! {
+			#[derive(Debug)]
+			pub struct TypedPacked {
+				pub a: u8,
+				pub b: u32
+			} repr(C, packed) typed as TypedPackedOffsets, TypedPackedSpans
+		}
+
+		let mut value = TypedPacked { a: 0, b: 0 };
+		let offsets = TypedPacked::offsets();
+
+		assert_eq!(offsets.b.offset(), 1);
+
+		let base = &mut value as *mut TypedPacked;
+		unsafe {
+			offsets.b.write_unaligned(base, 0xDEAD_BEEF);
+			assert_eq!(offsets.b.read_unaligned(base), 0xDEAD_BEEF);
+		}
+	}
+
+	#[test]
+	fn nested_offsets_compose() {
+		offsetable_struct! {
+			#[derive(Debug)]
+			pub struct Inner {
+				pub x: u32,
+				pub y: u32
+			} repr(C) as InnerOffsets, InnerSpans
+		}
+		offsetable_struct! {
+			#[derive(Debug)]
+			pub struct Outer {
+				pub head: u32,
+				pub inner: Inner
+			} repr(C) as OuterOffsets, OuterSpans
+		}
+
+		// inner sits at offset 4, its `y` field sits at offset 4 within `Inner`.
+		assert_eq!(Outer::offsets().inner, 4);
+		assert_eq!(
+			util::nested(Outer::offsets().inner, Inner::offsets().y),
+			8
+		);
+	}
+
+	#[test]
+	fn offsetable_trait_resolves_offsets() {
+		offsetable_struct! {
+			#[derive(Debug)]
+			pub struct Via {
+				pub a: u32,
+				pub b: u32
+			} repr(C) as ViaOffsets, ViaSpans
+		}
+
+		let offsets: <Via as Offsetable>::Offsets = <Via as Offsetable>::offsets();
+		assert_eq!(offsets.b, 4);
+	}
+
+	#[test]
+	fn generic_named_struct() {
+		offsetable_struct! {
+			#[derive(Debug)]
+			pub struct GenNamed<T: Copy> {
+				pub head: u32,
+				pub value: T
+			} repr(C) as GenNamedOffsets, GenNamedSpans
+		}
+
+		assert_eq!(GenNamed::<u32>::offsets().head, 0);
+		assert_eq!(GenNamed::<u32>::offsets().value, 4);
+		// a larger field pushes the offset out according to its own size/alignment.
+		assert_eq!(GenNamed::<[u32; 4]>::offsets().value, 4);
+		assert_eq!(GenNamed::<f64>::offsets().value, 8);
+	}
+
+	#[test]
+	fn tuple_struct_offsets() {
+		offsetable_struct! {
+			#[derive(Debug)]
+			pub struct TupleVertex(pub f32, pub [f32; 4], u8) repr(C) as TupleVertexOffsets, TupleVertexSpans
+		}
+
+		let offsets = TupleVertex::offsets();
+		assert_eq!(offsets.0, 0);
+		assert_eq!(offsets.1, 4);
+		assert_eq!(offsets.2, 20);
+
+		assert_eq!(TupleVertex::field_spans().1, (4, 20));
+		assert_eq!(TupleVertex::packed_size(), 24);
+	}
+
+	#[test]
+	fn generic_tuple_struct() {
+		offsetable_struct! {
+			#[derive(Debug)]
+			pub struct GenTuple<T: Copy>(pub u32, pub T) repr(C) as GenTupleOffsets, GenTupleSpans
+		}
+
+		assert_eq!(GenTuple::<u32>::offsets().1, 4);
+		assert_eq!(GenTuple::<f64>::offsets().1, 8);
+	}
+
+	#[test]
+	fn generic_struct_with_where_clause() {
+		offsetable_struct! {
+			#[derive(Debug)]
+			pub struct Where<T: Copy> {
+				pub head: u32,
+				pub value: T
+			} where T: Default repr(C) as WhereOffsets, WhereSpans
+		}
+
+		assert_eq!(Where::<u32>::offsets().head, 0);
+		assert_eq!(Where::<f64>::offsets().value, 8);
+	}
+
+	#[test]
+	fn typed_tuple_struct_projects() {
+		offsetable_struct! {
+			#[derive(Debug)]
+			pub struct TypedTuple(pub f32, pub u32) repr(C) typed as TypedTupleOffsets, TypedTupleSpans
+		}
+
+		let value = TypedTuple(1.0, 7);
+		let offsets = TypedTuple::offsets();
+
+		assert_eq!(offsets.1.offset(), 4);
+		let ptr = offsets.1.get_ptr(&value as *const TypedTuple);
+		assert_eq!(unsafe { *ptr }, 7);
+	}
 }
@@ -1,3 +1,23 @@
 pub const fn align_up(base: usize, align: usize) -> usize {
 	base.wrapping_add(align.wrapping_sub(1)) & !align.wrapping_sub(1)
-}
\ No newline at end of file
+}
+
+pub const fn align_min(a: usize, b: usize) -> usize {
+	if a < b {
+		a
+	} else {
+		b
+	}
+}
+
+pub const fn align_max(a: usize, b: usize) -> usize {
+	if a > b {
+		a
+	} else {
+		b
+	}
+}
+
+pub const fn nested(outer_offset: usize, inner_offset: usize) -> usize {
+	outer_offset + inner_offset
+}